@@ -79,6 +79,17 @@ impl Watch {
         }
     }
 
+    /// create a new watch with the given time and meridiem option, returning a [`ParseError`]
+    /// instead of silently coercing a malformed `time` to `0`.
+    pub fn try_new(time: &str, meridiem: bool) -> Result<Watch, ParseError> {
+        let secs = Watch::try_str_to_secs(time)?;
+        Ok(Watch {
+            start: secs,
+            meridiem,
+            ..Default::default()
+        })
+    }
+
     /// take a time string (e.g. "01:23:45 AM") and return the number of seconds
     pub fn str_to_secs(time: &str, is_time_span: bool) -> i64 {
         let pm = {
@@ -96,6 +107,48 @@ impl Watch {
         hours * 3600 + minutes * 60 + seconds
     }
 
+    /// take a time string (e.g. "01:23:45 AM") and return the number of seconds, or a
+    /// [`ParseError`] pinpointing the bad field instead of silently treating it as `0`.
+    pub fn try_str_to_secs(time: &str) -> Result<i64, ParseError> {
+        let pm = time.replace('.', "").to_uppercase().contains("PM");
+
+        let first = time.split(' ').next().unwrap_or("");
+        if first.is_empty() {
+            return Err(ParseError::EmptyField);
+        }
+
+        let mut fields = first.split(':');
+        let mut offset = 0usize;
+
+        let hours_str = fields.next().unwrap_or("");
+        let mut hours = parse_time_field(hours_str, offset)?;
+        offset += hours_str.len() + 1;
+
+        let minutes_str = fields.next().unwrap_or("0");
+        let minutes = parse_time_field(minutes_str, offset)?;
+        if minutes >= 60 {
+            return Err(ParseError::OutOfRange {
+                field: "minutes",
+                value: minutes,
+            });
+        }
+        offset += minutes_str.len() + 1;
+
+        let seconds_str = fields.next().unwrap_or("0");
+        let seconds = parse_time_field(seconds_str, offset)?;
+        if seconds >= 60 {
+            return Err(ParseError::OutOfRange {
+                field: "seconds",
+                value: seconds,
+            });
+        }
+
+        if pm {
+            hours += 12;
+        }
+        Ok(hours * 3600 + minutes * 60 + seconds)
+    }
+
     /// convert secs to string (HH:MM:SS format)
     pub fn secs_to_mil(secs: i64) -> String {
         let hours = secs / 3600 % 24;
@@ -124,6 +177,58 @@ impl Watch {
         format!("{:02}:{:02}:{:02} {}", hours, minutes, seconds, meridiem)
     }
 
+    /// parse a time span, accepting either a colon string (e.g. `"01:23:45"`) or a humantime-style
+    /// duration (e.g. `"1h 23m 45s"`, `"2days 30min"`, `"90s"`). A string containing `:` is always
+    /// treated as a colon string. Unit groups in a duration string are summed together; recognized
+    /// suffixes are `s`/`sec`/`second(s)`, `m`/`min`/`minute(s)`, `h`/`hr`/`hour(s)`, `d`/`day(s)`
+    /// and `w`/`week(s)`.
+    pub fn parse_duration(time: &str) -> i64 {
+        if time.contains(':') {
+            return Watch::str_to_secs(time, false);
+        }
+
+        let chars: Vec<char> = time.chars().collect();
+        let mut total = 0i64;
+        let mut i = 0;
+        while i < chars.len() {
+            while i < chars.len() && chars[i].is_whitespace() {
+                i += 1;
+            }
+            let number_start = i;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            if number_start == i {
+                break;
+            }
+            let number: i64 = chars[number_start..i]
+                .iter()
+                .collect::<String>()
+                .parse()
+                .unwrap_or(0);
+
+            while i < chars.len() && chars[i].is_whitespace() {
+                i += 1;
+            }
+            let unit_start = i;
+            while i < chars.len() && chars[i].is_alphabetic() {
+                i += 1;
+            }
+            let unit = chars[unit_start..i].iter().collect::<String>().to_lowercase();
+
+            let multiplier = match unit.as_str() {
+                "s" | "sec" | "secs" | "second" | "seconds" => 1,
+                "m" | "min" | "mins" | "minute" | "minutes" => 60,
+                "h" | "hr" | "hrs" | "hour" | "hours" => 3600,
+                "d" | "day" | "days" => 86400,
+                "w" | "week" | "weeks" => 604800,
+                _ => 1,
+            };
+            total += number * multiplier;
+        }
+        total
+    }
+
     /// convert diff seconds to num of days later or before
     pub fn diff_to_days(diff: i64) -> String {
         let days = diff / 86400;
@@ -139,10 +244,94 @@ impl Watch {
         self.start + self.offset
     }
 
+    /// add `rhs` seconds to the offset, returning `None` instead of overflowing if either the
+    /// offset itself or `start + offset` would overflow an [`i64`].
+    pub fn checked_add(self, rhs: i64) -> Option<Watch> {
+        let offset = self.offset.checked_add(rhs)?;
+        self.start.checked_add(offset)?;
+        Some(Watch { offset, ..self })
+    }
+
+    /// subtract `rhs` seconds from the offset, returning `None` instead of overflowing if either
+    /// the offset itself or `start + offset` would overflow an [`i64`].
+    pub fn checked_sub(self, rhs: i64) -> Option<Watch> {
+        let offset = self.offset.checked_sub(rhs)?;
+        self.start.checked_add(offset)?;
+        Some(Watch { offset, ..self })
+    }
+
     /// change the meridiem option
     pub fn change_meridiem(&mut self, meridiem: bool) {
         self.meridiem = meridiem;
     }
+
+    /// the signed difference, in seconds, between this watch's and `other`'s [`add_offset()`](Watch::add_offset).
+    /// Positive means this watch is later than `other`.
+    pub fn signed_duration_since(&self, other: &Watch) -> i64 {
+        self.add_offset() - other.add_offset()
+    }
+
+    /// format the watch's current time using a `strftime`-style pattern. Supported specifiers are
+    /// `%H` (00-23), `%I` (01-12), `%M`, `%S`, `%p`/`%P` (AM/PM, upper/lower), `%d` (signed day
+    /// offset) and the literal `%%`. Any other specifier is passed through unchanged.
+    pub fn format(&self, fmt: &str) -> String {
+        let end = (self.add_offset() % 86400 + 86400) % 86400;
+        let diff = self.offset + self.start - end;
+        let days = diff / 86400;
+
+        let hours24 = end / 3600 % 24;
+        let minutes = (end % 3600) / 60;
+        let seconds = end % 60;
+        let mut hours12 = hours24 % 12;
+        if hours12 == 0 {
+            hours12 = 12;
+        }
+        let pm = hours24 >= 12;
+
+        let mut out = String::new();
+        let mut chars = fmt.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                out.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('H') => out.push_str(&format!("{:02}", hours24)),
+                Some('I') => out.push_str(&format!("{:02}", hours12)),
+                Some('M') => out.push_str(&format!("{:02}", minutes)),
+                Some('S') => out.push_str(&format!("{:02}", seconds)),
+                Some('p') => out.push_str(if pm { "PM" } else { "AM" }),
+                Some('P') => out.push_str(if pm { "pm" } else { "am" }),
+                Some('d') => out.push_str(&days.to_string()),
+                Some('%') => out.push('%'),
+                Some(other) => {
+                    out.push('%');
+                    out.push(other);
+                }
+                None => out.push('%'),
+            }
+        }
+        out
+    }
+
+    /// wrap this watch together with a format string into a [`WatchFormat`] that can be printed
+    /// directly via [`fmt::Display`].
+    pub fn with_format(self, fmt: &str) -> WatchFormat<'_> {
+        WatchFormat { watch: self, fmt }
+    }
+}
+
+/// a [`Watch`] paired with a `strftime`-style format string, produced by [`Watch::with_format`].
+/// Implements [`fmt::Display`] by delegating to [`Watch::format`].
+pub struct WatchFormat<'a> {
+    watch: Watch,
+    fmt: &'a str,
+}
+
+impl fmt::Display for WatchFormat<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.watch.format(self.fmt))
+    }
 }
 
 // Operations
@@ -150,10 +339,7 @@ impl Add<i64> for Watch {
     type Output = Watch;
 
     fn add(self, rhs: i64) -> Self::Output {
-        Watch {
-            offset: self.offset + rhs,
-            ..self
-        }
+        self.checked_add(rhs).expect("Watch offset overflowed")
     }
 }
 
@@ -161,10 +347,7 @@ impl Sub<i64> for Watch {
     type Output = Watch;
 
     fn sub(self, rhs: i64) -> Self::Output {
-        Watch {
-            offset: self.offset - rhs,
-            ..self
-        }
+        self.checked_sub(rhs).expect("Watch offset overflowed")
     }
 }
 
@@ -172,7 +355,7 @@ impl Add<&str> for Watch {
     type Output = Watch;
 
     fn add(self, rhs: &str) -> Self::Output {
-        let secs = Watch::str_to_secs(rhs, false);
+        let secs = Watch::parse_duration(rhs);
         self + secs
     }
 }
@@ -181,11 +364,37 @@ impl Sub<&str> for Watch {
     type Output = Watch;
 
     fn sub(self, rhs: &str) -> Self::Output {
-        let secs = Watch::str_to_secs(rhs, false);
+        let secs = Watch::parse_duration(rhs);
         self - secs
     }
 }
 
+impl Sub<Watch> for Watch {
+    type Output = i64;
+
+    /// the signed difference, in seconds, between the two watches. Equivalent to
+    /// `self.signed_duration_since(&rhs)`.
+    fn sub(self, rhs: Watch) -> Self::Output {
+        self.signed_duration_since(&rhs)
+    }
+}
+
+impl Add<std::time::Duration> for Watch {
+    type Output = Watch;
+
+    fn add(self, rhs: std::time::Duration) -> Self::Output {
+        self + rhs.as_secs() as i64
+    }
+}
+
+impl Sub<std::time::Duration> for Watch {
+    type Output = Watch;
+
+    fn sub(self, rhs: std::time::Duration) -> Self::Output {
+        self - rhs.as_secs() as i64
+    }
+}
+
 // Custom trait that will be implemented by i64 and &str
 trait AddableToWatch {}
 
@@ -211,6 +420,52 @@ where
     }
 }
 
+/// parse a single `:`-delimited time field, reporting the byte offset of the first invalid
+/// character into the original string. A field of digits too large for an [`i64`] is reported
+/// as [`ParseError::InvalidCharacter`] at the field's first byte.
+fn parse_time_field(field: &str, offset: usize) -> Result<i64, ParseError> {
+    if field.is_empty() {
+        return Err(ParseError::EmptyField);
+    }
+    for (i, c) in field.char_indices() {
+        if !c.is_ascii_digit() {
+            return Err(ParseError::InvalidCharacter(offset + i));
+        }
+    }
+    field
+        .parse::<i64>()
+        .map_err(|_| ParseError::InvalidCharacter(offset))
+}
+
+/// the error returned by the `try_*` parsing functions, reporting what was wrong and, where
+/// possible, where in the input string it went wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// an invalid (non-digit) character was found at the given byte offset into the input, or
+    /// a field of digits too large for an [`i64`] starts at that offset
+    InvalidCharacter(usize),
+    /// a required field was missing or empty
+    EmptyField,
+    /// a field was parsed but its value is out of range (e.g. minutes/seconds >= 60)
+    OutOfRange { field: &'static str, value: i64 },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::InvalidCharacter(offset) => {
+                write!(f, "invalid character at byte offset {}", offset)
+            }
+            ParseError::EmptyField => write!(f, "missing or empty time field"),
+            ParseError::OutOfRange { field, value } => {
+                write!(f, "{} out of range: {}", field, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 // Display and Formatting
 impl fmt::Display for Watch {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -321,6 +576,97 @@ mod tests {
         assert_eq!(format!("{}", watch), "03:47:20 AM -1157 days");
     }
 
+    #[test]
+    fn custom_format() {
+        let watch = Watch::new("13:33:23", false);
+        assert_eq!(watch.format("%H:%M:%S"), "13:33:23");
+        assert_eq!(watch.format("%I:%M %p"), "01:33 PM");
+        assert_eq!(watch.format("%I:%M %P"), "01:33 pm");
+        assert_eq!(watch.format("100%%"), "100%");
+    }
+
+    #[test]
+    fn custom_format_day_offset() {
+        let mut watch = Watch::new("13:33:23", false);
+        watch += "23:44:03";
+        assert_eq!(watch.format("day %d at %H:%M"), "day 1 at 13:17");
+        assert_eq!(format!("{}", watch.with_format("%H:%M:%S")), "13:17:26");
+    }
+
+    #[test]
+    fn humantime_duration_adding() {
+        let mut watch = Watch::new("13:33:23", false);
+        watch += "1h 23m 45s";
+        assert_eq!(format!("{}", watch), "14:57:08");
+
+        watch += "2days 30min";
+        assert_eq!(format!("{}", watch), "15:27:08 +2 days");
+    }
+
+    #[test]
+    fn humantime_duration_single_unit() {
+        let mut watch = Watch::new("13:33:23", false);
+        watch += "90s";
+        assert_eq!(format!("{}", watch), "13:34:53");
+    }
+
+    #[test]
+    fn checked_add_overflows_to_none() {
+        let watch = Watch::new("13:33:23", false);
+        assert!(watch.checked_add(i64::MAX).is_none());
+        assert!(watch.checked_sub(i64::MIN).is_none());
+    }
+
+    #[test]
+    fn checked_add_within_range() {
+        let watch = Watch::new("13:33:23", false);
+        let added = watch.checked_add(1000).unwrap();
+        assert_eq!(format!("{}", added), "13:50:03");
+    }
+
+    #[test]
+    fn try_new_reports_invalid_character() {
+        let err = Watch::try_new("1x:99:zz", false).unwrap_err();
+        assert_eq!(err, super::ParseError::InvalidCharacter(1));
+    }
+
+    #[test]
+    fn try_new_reports_out_of_range() {
+        let err = Watch::try_new("13:99:00", false).unwrap_err();
+        assert_eq!(
+            err,
+            super::ParseError::OutOfRange {
+                field: "minutes",
+                value: 99
+            }
+        );
+    }
+
+    #[test]
+    fn try_new_accepts_valid_time() {
+        let watch = Watch::try_new("13:33:23", false).unwrap();
+        assert_eq!(format!("{}", watch), "13:33:23");
+    }
+
+    #[test]
+    fn signed_duration_since_and_sub() {
+        let later = Watch::new("13:33:23", false);
+        let earlier = Watch::new("12:00:00", false);
+        assert_eq!(later.signed_duration_since(&earlier), 5603);
+        assert_eq!(earlier.signed_duration_since(&later), -5603);
+        assert_eq!(later - earlier, 5603);
+    }
+
+    #[test]
+    fn std_duration_adding_and_subtracting() {
+        let watch = Watch::new("13:33:23", false);
+        let added = watch + std::time::Duration::from_secs(1000);
+        assert_eq!(format!("{}", added), "13:50:03");
+
+        let subtracted = watch - std::time::Duration::from_secs(1000);
+        assert_eq!(format!("{}", subtracted), "13:16:43");
+    }
+
     #[test]
     fn changing_meridiem() {
         let mut watch = Watch::new("13:34", true);